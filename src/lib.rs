@@ -108,18 +108,23 @@
 //! - MIT
 //! - Apache 2.0
 
-pub use mio_serial::{DataBits, FlowControl, Parity, StopBits};
+pub use mio_serial::{ClearBuffer, DataBits, FlowControl, Parity, StopBits};
 
-use bevy::app::{App, Plugin, PostUpdate, PreUpdate};
+use bevy::app::{App, AppExit, Plugin, PostUpdate, PreUpdate};
 use bevy::ecs::event::{Event, EventReader, EventWriter};
-use bevy::ecs::system::{In, IntoSystem, Res, ResMut, Resource};
-use mio::{Events, Interest, Poll, Token};
+use bevy::ecs::schedule::IntoSystemConfigs;
+use bevy::ecs::system::{In, IntoSystem, Res, ResMut, Resource, SystemParam};
+use mio::{Events, Interest, Poll, Registry, Token};
 use mio_serial::SerialStream;
 use once_cell::sync::OnceCell;
+use serialport::SerialPort;
 use std::collections::HashMap;
 use std::io::{ErrorKind, Read, Write};
-use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
 
 /// Plugin that can be added to Bevy
 pub struct SerialPlugin {
@@ -166,6 +171,13 @@ pub struct SerialSetting {
     pub stop_bits: StopBits,
     /// Amount of time to wait to receive data before timing out
     pub timeout: Duration,
+    /// Reconnection policy to use if this port disconnects; `None` (the
+    /// default) leaves the port disconnected until it is reopened, e.g. via
+    /// `SerialOpenEvent`
+    pub reconnect: Option<ReconnectPolicy>,
+    /// How bytes read from this port are split into discrete packets before
+    /// being emitted as `SerialReadEvent`s
+    pub framing: FramingMode,
 }
 
 impl Default for SerialSetting {
@@ -179,10 +191,274 @@ impl Default for SerialSetting {
             parity: Parity::None,
             stop_bits: StopBits::One,
             timeout: Duration::from_millis(0),
+            reconnect: None,
+            framing: FramingMode::Raw,
         }
     }
 }
 
+/// How bytes read from a serial port are split into discrete packets before
+/// being emitted as `SerialReadEvent`s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FramingMode {
+    /// Emit an event for whatever bytes a single poll iteration read, with
+    /// no reassembly. This is the default, and the previous behavior of
+    /// this crate.
+    Raw,
+    /// Split on a delimiter byte (e.g. `b'\n'`); the delimiter itself is
+    /// dropped from the emitted frame.
+    Delimiter(u8),
+    /// Frames are `[length header][payload]`; `header_len` is the size of
+    /// the length header and `endianness` how it encodes the payload length.
+    LengthPrefixed {
+        header_len: LengthHeaderSize,
+        endianness: Endianness,
+    },
+    /// Consistent Overhead Byte Stuffing frames, terminated by a `0x00`
+    /// delimiter byte.
+    Cobs,
+}
+
+/// Size in bytes of a `FramingMode::LengthPrefixed` length header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthHeaderSize {
+    One,
+    Two,
+    Four,
+}
+
+impl LengthHeaderSize {
+    fn byte_len(self) -> usize {
+        match self {
+            LengthHeaderSize::One => 1,
+            LengthHeaderSize::Two => 2,
+            LengthHeaderSize::Four => 4,
+        }
+    }
+}
+
+/// Byte order of a `FramingMode::LengthPrefixed` length header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endianness {
+    Big,
+    Little,
+}
+
+impl Endianness {
+    fn read_len(self, header: &[u8]) -> usize {
+        match self {
+            Endianness::Big => header
+                .iter()
+                .fold(0usize, |acc, &b| (acc << 8) | b as usize),
+            Endianness::Little => header
+                .iter()
+                .rev()
+                .fold(0usize, |acc, &b| (acc << 8) | b as usize),
+        }
+    }
+}
+
+/// Upper bound on how large a port's pending partial-frame buffer is allowed
+/// to grow. Without this, a device that never sends a delimiter/terminator,
+/// or that reports a bogus `LengthPrefixed` header, would make `buffer` grow
+/// without bound on every read.
+const MAX_FRAMING_BUFFER_LEN: usize = 1024 * 1024;
+
+/// Split `incoming` (freshly read bytes) appended to this port's pending
+/// partial frame into zero or more complete frames, per `mode`. Bytes that
+/// don't yet form a complete frame are left in `buffer` for the next read.
+fn frame_bytes(buffer: &mut Vec<u8>, incoming: &[u8], mode: FramingMode) -> Vec<Vec<u8>> {
+    if incoming.is_empty() && buffer.is_empty() {
+        return Vec::new();
+    }
+    buffer.extend_from_slice(incoming);
+
+    let frames = match mode {
+        FramingMode::Raw => {
+            if buffer.is_empty() {
+                Vec::new()
+            } else {
+                vec![std::mem::take(buffer)]
+            }
+        }
+        FramingMode::Delimiter(delimiter) => {
+            let mut frames = Vec::new();
+            while let Some(pos) = buffer.iter().position(|&b| b == delimiter) {
+                frames.push(buffer.drain(..pos).collect());
+                buffer.drain(..1); // drop the delimiter itself
+            }
+            frames
+        }
+        FramingMode::LengthPrefixed {
+            header_len,
+            endianness,
+        } => {
+            let header_len = header_len.byte_len();
+            let mut frames = Vec::new();
+            loop {
+                if buffer.len() < header_len {
+                    break;
+                }
+                let payload_len = endianness.read_len(&buffer[..header_len]);
+                let frame_len = header_len + payload_len;
+                if buffer.len() < frame_len {
+                    break;
+                }
+                let frame: Vec<u8> = buffer.drain(..frame_len).collect();
+                frames.push(frame[header_len..].to_vec());
+            }
+            frames
+        }
+        FramingMode::Cobs => {
+            let mut frames = Vec::new();
+            while let Some(pos) = buffer.iter().position(|&b| b == 0) {
+                let encoded: Vec<u8> = buffer.drain(..=pos).collect();
+                match cobs_decode(&encoded[..encoded.len() - 1]) {
+                    Ok(decoded) => frames.push(decoded),
+                    Err(()) => eprintln!("Failed to decode COBS frame, dropping it"),
+                }
+            }
+            frames
+        }
+    };
+
+    if buffer.len() > MAX_FRAMING_BUFFER_LEN {
+        eprintln!(
+            "Framing buffer exceeded {MAX_FRAMING_BUFFER_LEN} bytes without completing a frame, dropping it"
+        );
+        buffer.clear();
+    }
+    frames
+}
+
+/// Decode a Consistent Overhead Byte Stuffing frame, excluding its
+/// terminating `0x00` delimiter.
+fn cobs_decode(encoded: &[u8]) -> Result<Vec<u8>, ()> {
+    let mut decoded = Vec::with_capacity(encoded.len());
+    let mut pos = 0;
+    while pos < encoded.len() {
+        let code = encoded[pos] as usize;
+        if code == 0 || pos + code > encoded.len() + 1 {
+            return Err(());
+        }
+        pos += 1;
+        for _ in 1..code {
+            let Some(&byte) = encoded.get(pos) else {
+                return Err(());
+            };
+            decoded.push(byte);
+            pos += 1;
+        }
+        if code < 0xFF && pos < encoded.len() {
+            decoded.push(0);
+        }
+    }
+    Ok(decoded)
+}
+
+/// Exponential backoff policy used by the opt-in reconnection subsystem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReconnectPolicy {
+    /// Delay before the first reconnection attempt, and the starting point
+    /// for the exponential backoff
+    pub initial_backoff: Duration,
+    /// Upper bound the backoff delay is capped at
+    pub max_backoff: Duration,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// Double `backoff`, capped at `self.max_backoff`.
+    fn next_backoff(&self, backoff: Duration) -> Duration {
+        (backoff * 2).min(self.max_backoff)
+    }
+}
+
+/// Bevy's event type sent when a disconnected serial port is successfully reopened
+#[derive(Event)]
+pub struct SerialConnectedEvent(pub String);
+
+/// Bevy's event type sent when a serial port's connection is lost
+#[derive(Event)]
+pub struct SerialDisconnectedEvent(pub String);
+
+/// Information about a serial port detected on the system, as reported by
+/// `serialport::available_ports()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SerialPortInfo {
+    /// The port name, usually the device path
+    pub port_name: String,
+    /// USB vendor ID, if this is a USB serial device
+    pub vid: Option<u16>,
+    /// USB product ID, if this is a USB serial device
+    pub pid: Option<u16>,
+    /// USB serial number, if this is a USB serial device and reports one
+    pub serial_number: Option<String>,
+    /// USB manufacturer string, if this is a USB serial device and reports one
+    pub manufacturer: Option<String>,
+}
+
+impl From<serialport::SerialPortInfo> for SerialPortInfo {
+    fn from(info: serialport::SerialPortInfo) -> Self {
+        match info.port_type {
+            serialport::SerialPortType::UsbPort(usb) => Self {
+                port_name: info.port_name,
+                vid: Some(usb.vid),
+                pid: Some(usb.pid),
+                serial_number: usb.serial_number,
+                manufacturer: usb.manufacturer,
+            },
+            _ => Self {
+                port_name: info.port_name,
+                vid: None,
+                pid: None,
+                serial_number: None,
+                manufacturer: None,
+            },
+        }
+    }
+}
+
+/// Query the OS for currently attached serial ports. Ports are not opened by
+/// this call; use the returned `port_name` with `SerialSetting` (or
+/// `SerialOpenEvent`) to open one.
+pub fn query_available_ports() -> Vec<SerialPortInfo> {
+    serialport::available_ports()
+        .unwrap_or_else(|e| {
+            eprintln!("Failed to enumerate available serial ports: {e:?}");
+            Vec::new()
+        })
+        .into_iter()
+        .map(SerialPortInfo::from)
+        .collect()
+}
+
+/// Resource caching the serial ports detected by `query_available_ports()`.
+/// Populated when `SerialPlugin` is built; call `refresh` to update it on
+/// demand, e.g. right before showing a device-picker UI.
+#[derive(Resource, Default)]
+pub struct AvailablePorts(Vec<SerialPortInfo>);
+
+impl AvailablePorts {
+    /// The serial ports detected as of the last `refresh`.
+    pub fn ports(&self) -> &[SerialPortInfo] {
+        &self.0
+    }
+
+    /// Re-query the OS for currently attached serial ports.
+    pub fn refresh(&mut self) {
+        self.0 = query_available_ports();
+    }
+}
+
 /// Bevy's event type to read serial port
 #[derive(Event)]
 pub struct SerialReadEvent(pub String, pub Vec<u8>);
@@ -197,32 +473,230 @@ struct SerialStreamLabeled {
     stream: SerialStream,
     label: String,
     connected: bool,
+    /// kept around so a dropped connection can be reopened the same way it
+    /// was originally opened
+    setting: SerialSetting,
+    /// present while disconnected and `setting.reconnect` is set; tracks the
+    /// current backoff and when the next reconnection attempt is due
+    reconnect_state: Option<ReconnectState>,
+    /// set while a reconnection attempt is in flight on a worker thread, so
+    /// `reconnect_serial` doesn't dispatch a second attempt for the same port
+    /// before the first one reports back
+    reconnecting: bool,
+    /// bytes read so far that don't yet form a complete frame under
+    /// `setting.framing`
+    framing_buffer: Vec<u8>,
 }
 
-/// Module scope global singleton to store serial ports
-static SERIALS: OnceCell<Vec<Mutex<SerialStreamLabeled>>> = OnceCell::new();
+/// Per-port exponential backoff state for the reconnection subsystem
+#[derive(Debug)]
+struct ReconnectState {
+    backoff: Duration,
+    next_attempt: Instant,
+}
 
-/// Context to poll serial read event with `Poll` in `mio` crate
+/// Module scope global singleton to store serial ports.
+///
+/// Wrapped in a `RwLock` so ports can be opened and closed at runtime: a
+/// write lock is only needed while a port is put into or taken out of a
+/// slot, while reading/writing an already-open port only needs the
+/// per-port `Mutex`.
+static SERIALS: OnceCell<RwLock<Vec<Option<Mutex<SerialStreamLabeled>>>>> = OnceCell::new();
+
+/// Handle used from ECS systems to register/deregister streams with the
+/// `Poll` owned by the background reader thread. `mio::Registry` is cloneable
+/// and safe to use from a thread other than the one that owns its `Poll`.
 #[derive(Resource)]
 struct MioContext {
-    poll: Poll,
-    events: Events,
+    registry: Registry,
 }
 
-impl MioContext {
-    /// poll serial read event (should timeout not to block other systems)
-    fn poll(&mut self) {
-        self.poll
-            .poll(&mut self.events, Some(Duration::from_micros(1)))
-            .unwrap_or_else(|e| {
-                panic!("Failed to poll events: {e:?}");
-            });
-    }
+/// How long the background reader thread blocks in a single `Poll::poll`
+/// call before re-checking its shutdown flag. Bounds both serial read
+/// latency and shutdown latency.
+const POLL_TIMEOUT: Duration = Duration::from_millis(50);
+
+/// A message sent from a background thread (the reader thread, or a
+/// one-off reconnect worker) to `read_serial`.
+enum SerialChannelMessage {
+    /// A complete frame was read from the named port
+    Read(String, Vec<u8>),
+    /// The named port's connection was lost
+    Disconnected(String),
+    /// A reconnect worker thread successfully reopened the named port
+    Reconnected(String),
+}
+
+/// Receiving end of the channel the background reader thread (and any
+/// in-flight reconnect worker threads) push `SerialChannelMessage`s into.
+#[derive(Resource)]
+struct SerialReadChannel {
+    // `mpsc::Receiver` is never `Sync`, but `Resource` requires `Send + Sync`;
+    // `Mutex` is only ever locked briefly from `read_serial`, so this adds no
+    // real contention.
+    receiver: Mutex<mpsc::Receiver<SerialChannelMessage>>,
 }
 
-/// Component to get an index of serial port based on the label
+/// Sending end of the same channel, kept as its own resource so systems
+/// other than the background reader thread (e.g. `reconnect_serial`'s worker
+/// threads) can report results back without blocking the frame they run on.
+#[derive(Resource, Clone)]
+struct SerialChannelSender(mpsc::Sender<SerialChannelMessage>);
+
+/// Owns the background reader thread, and shuts it down cleanly when dropped
+/// (e.g. when the app exits and resources are dropped).
 #[derive(Resource)]
-struct Indices(HashMap<String, usize>);
+struct SerialReaderThread {
+    shutdown: Arc<AtomicBool>,
+    handle: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl Drop for SerialReaderThread {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::Relaxed);
+        let handle = self.handle.get_mut().unwrap_or_else(|e| {
+            panic!("Failed to lock serial reader thread handle: {e:?}");
+        });
+        if let Some(handle) = handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Flip the reader thread's shutdown flag as soon as `AppExit` is observed,
+/// rather than relying solely on `SerialReaderThread`'s `Drop` impl, which
+/// only runs once the `World` (and its resources) are actually dropped —
+/// something not every runner (e.g. some test/headless harnesses) does on
+/// exit. `Drop` remains as a secondary safety net.
+fn shutdown_reader_on_exit(
+    mut ev_exit: EventReader<AppExit>,
+    reader_thread: Res<SerialReaderThread>,
+) {
+    if ev_exit.read().next().is_some() {
+        reader_thread.shutdown.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Spawn the background thread that owns `poll`/`events` and blocks on
+/// `Poll::poll` with a real timeout, pushing the bytes (or disconnects) it
+/// reads into `sender`. Following the pattern of moving serial input onto its
+/// own thread feeding a channel, this decouples serial read latency from
+/// frame rate and avoids busy-polling the OS from `PreUpdate`.
+fn spawn_reader_thread(
+    mut poll: Poll,
+    mut events: Events,
+    sender: mpsc::Sender<SerialChannelMessage>,
+) -> SerialReaderThread {
+    let shutdown = Arc::new(AtomicBool::new(false));
+    let thread_shutdown = shutdown.clone();
+
+    let handle = thread::Builder::new()
+        .name("bevy_serial reader".to_string())
+        .spawn(move || {
+            while !thread_shutdown.load(Ordering::Relaxed) {
+                match poll.poll(&mut events, Some(POLL_TIMEOUT)) {
+                    Ok(()) => {}
+                    Err(ref e) if e.kind() == ErrorKind::Interrupted => continue,
+                    Err(e) => {
+                        eprintln!("Failed to poll serial events: {e:?}");
+                        continue;
+                    }
+                }
+
+                for event in events.iter() {
+                    if event.is_readable() {
+                        read_port(event.token().0, &sender);
+                    }
+                }
+            }
+        })
+        .unwrap_or_else(|e| {
+            panic!("Failed to spawn serial reader thread: {e:?}");
+        });
+
+    SerialReaderThread {
+        shutdown,
+        handle: Mutex::new(Some(handle)),
+    }
+}
+
+/// Read and frame whatever is available on the port registered under
+/// `token`, forwarding complete frames (or a disconnect notice) to `sender`.
+/// Runs on the background reader thread.
+fn read_port(token: usize, sender: &mpsc::Sender<SerialChannelMessage>) {
+    let Some(serials) = SERIALS.get() else {
+        return;
+    };
+    let slots = serials.read().unwrap_or_else(|e| {
+        panic!("Failed to lock SERIALS for reading: {e:?}");
+    });
+    let Some(serial_mtx) = slots.get(token).and_then(|slot| slot.as_ref()) else {
+        return;
+    };
+
+    let mut buffer = vec![0_u8; DEFAULT_READ_BUFFER_LEN];
+    let mut bytes_read = 0;
+    loop {
+        // try to get lock of mutex and send data to event
+        let Ok(mut serial) = serial_mtx.lock() else {
+            return;
+        };
+        if !serial.connected {
+            eprintln!("{} connection has closed", serial.label);
+            return;
+        }
+        match serial.stream.read(&mut buffer[bytes_read..]) {
+            Ok(0) => {
+                eprintln!("read connection closed");
+                if mark_disconnected(&mut serial) {
+                    let _ = sender.send(SerialChannelMessage::Disconnected(serial.label.clone()));
+                }
+                return;
+            }
+            // read data successfully
+            // if buffer is full, maybe there is more data to read
+            Ok(n) => {
+                bytes_read += n;
+                if bytes_read == buffer.len() {
+                    buffer.resize(buffer.len() + DEFAULT_READ_BUFFER_LEN, 0);
+                }
+            }
+            // would block indicates no more data to read
+            Err(ref e) if e.kind() == ErrorKind::WouldBlock => {
+                let label = serial.label.clone();
+                let read: Vec<u8> = buffer.drain(..bytes_read).collect();
+                let framing = serial.setting.framing;
+                for frame in frame_bytes(&mut serial.framing_buffer, &read, framing) {
+                    let _ = sender.send(SerialChannelMessage::Read(label.clone(), frame));
+                }
+                return;
+            }
+            // if interrupted, we should continue readings
+            Err(ref e) if e.kind() == ErrorKind::Interrupted => {
+                continue;
+            }
+            // other errors are fatal
+            Err(e) => {
+                eprintln!("Failed to read serial port {}: {}", serial.label, e);
+                if mark_disconnected(&mut serial) {
+                    let _ = sender.send(SerialChannelMessage::Disconnected(serial.label.clone()));
+                }
+                return;
+            }
+        }
+    }
+}
+
+/// Component to get an index of serial port based on the label.
+///
+/// `free` tracks slots in `SERIALS` that have been vacated by
+/// `SerialCloseEvent` so a later `SerialOpenEvent` can reuse the token
+/// instead of growing the registry forever.
+#[derive(Resource, Default)]
+struct Indices {
+    map: HashMap<String, usize>,
+    free: Vec<usize>,
+}
 
 /// The size of read buffer for one read system call
 const DEFAULT_READ_BUFFER_LEN: usize = 2048;
@@ -230,126 +704,553 @@ const DEFAULT_READ_BUFFER_LEN: usize = 2048;
 impl Plugin for SerialPlugin {
     fn build(&self, app: &mut App) {
         let poll = Poll::new().unwrap();
-        let events = Events::with_capacity(self.settings.len());
-        let mio_ctx = MioContext { poll, events };
-        let mut serials: Vec<Mutex<SerialStreamLabeled>> = vec![];
-        let mut indices = Indices(HashMap::new());
-
-        for (i, setting) in self.settings.iter().enumerate() {
-            // create serial port builder from `serialport` crate
-            let port_builder = serialport::new(&setting.port_name, setting.baud_rate)
-                .data_bits(setting.data_bits)
-                .flow_control(setting.flow_control)
-                .parity(setting.parity)
-                .stop_bits(setting.stop_bits)
-                .timeout(setting.timeout);
-
-            // create `mio_serial::SerailStream` from `seriaport` builder
-            let mut stream = SerialStream::open(&port_builder).unwrap_or_else(|e| {
-                panic!("Failed to open serial port {}\n{:?}", setting.port_name, e);
-            });
-
-            // token index is same as index of vec
-            mio_ctx
-                .poll
-                .registry()
-                .register(&mut stream, Token(i), Interest::READABLE)
-                .unwrap_or_else(|e| {
-                    panic!("Failed to register stream to poll : {e:?}");
-                });
-
-            // if label is set, use label as a nickname of serial
-            // if not, use `port_name` as a nickname
-            let label = if let Some(label) = &setting.label {
-                label.clone()
-            } else {
-                setting.port_name.clone()
-            };
+        let events = Events::with_capacity(self.settings.len().max(16));
+        let registry = poll.registry().try_clone().unwrap_or_else(|e| {
+            panic!("Failed to clone poll registry: {e:?}");
+        });
+        let mut mio_ctx = MioContext { registry };
+        let mut indices = Indices::default();
 
-            // store indices and serials
-            indices.0.insert(label.clone(), i);
-            serials.push(Mutex::new(SerialStreamLabeled {
-                stream,
-                label,
-                connected: true,
-            }));
+        for setting in self.settings.iter() {
+            open_serial_port(setting, &mut mio_ctx, &mut indices);
         }
 
-        // set to global variables lazily
-        SERIALS.set(serials).unwrap_or_else(|e| {
-            panic!("Failed to set SerialStream to global variable: {e:?}");
-        });
+        let (sender, receiver) = mpsc::channel();
+        let reader_thread = spawn_reader_thread(poll, events, sender.clone());
+        let channel_sender = SerialChannelSender(sender);
+
+        let mut available_ports = AvailablePorts::default();
+        available_ports.refresh();
 
         app.insert_resource(mio_ctx)
             .insert_resource(indices)
+            .insert_resource(available_ports)
+            .insert_resource(SerialReadChannel {
+                receiver: Mutex::new(receiver),
+            })
+            .insert_resource(channel_sender)
+            .insert_resource(reader_thread)
             .add_event::<SerialReadEvent>()
             .add_event::<SerialWriteEvent>()
-            .add_systems(PreUpdate, read_serial.pipe(self.on_read_error))
-            .add_systems(PostUpdate, write_serial.pipe(self.on_write_error));
+            .add_event::<SerialOpenEvent>()
+            .add_event::<SerialCloseEvent>()
+            .add_event::<SerialConnectedEvent>()
+            .add_event::<SerialDisconnectedEvent>()
+            .add_event::<SerialControlEvent>()
+            .add_event::<SerialReconfigureEvent>()
+            .add_event::<SerialClearEvent>()
+            .add_systems(
+                PreUpdate,
+                (
+                    open_serial,
+                    close_serial,
+                    reconnect_serial,
+                    read_serial.pipe(self.on_read_error),
+                )
+                    .chain(),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    control_serial,
+                    reconfigure_serial,
+                    clear_serial,
+                    write_serial.pipe(self.on_write_error),
+                )
+                    .chain(),
+            )
+            .add_systems(PostUpdate, shutdown_reader_on_exit);
     }
 }
 
-/// Poll serial read event with `Poll` in `mio` crate.
-/// If any data has come to serial, `SerialReadEvent` is sent to the system subscribing it.
-fn read_serial(
-    mut ev_receive_serial: EventWriter<SerialReadEvent>,
+/// Open `setting` as a new `mio_serial::SerialStream`, register it with
+/// `mio_ctx`'s poll registry and insert it into `indices` under a fresh (or
+/// reused) token. Used both for the ports listed in `SerialPlugin::settings`
+/// at startup and for `SerialOpenEvent`s received at runtime — since the
+/// latter is how a running app opens a hot-plugged device that may not be
+/// present yet, a failed open is logged and skipped rather than panicking
+/// the whole app.
+fn open_serial_port(setting: &SerialSetting, mio_ctx: &mut MioContext, indices: &mut Indices) {
+    // create serial port builder from `serialport` crate
+    let port_builder = serialport::new(&setting.port_name, setting.baud_rate)
+        .data_bits(setting.data_bits)
+        .flow_control(setting.flow_control)
+        .parity(setting.parity)
+        .stop_bits(setting.stop_bits)
+        .timeout(setting.timeout);
+
+    // create `mio_serial::SerailStream` from `seriaport` builder
+    let mut stream = match SerialStream::open(&port_builder) {
+        Ok(stream) => stream,
+        Err(e) => {
+            eprintln!("Failed to open serial port {}: {e:?}", setting.port_name);
+            return;
+        }
+    };
+
+    // if label is set, use label as a nickname of serial
+    // if not, use `port_name` as a nickname
+    let label = if let Some(label) = &setting.label {
+        label.clone()
+    } else {
+        setting.port_name.clone()
+    };
+
+    // a label must map to exactly one open port; close out the old one first
+    // so `indices.map`/`SERIALS` never silently lose track of it
+    if indices.map.contains_key(&label) {
+        eprintln!("Label {label} is already open, closing the existing port before reopening it");
+        close_serial_port(&label, mio_ctx, indices);
+    }
+
+    let serials = SERIALS.get_or_init(|| RwLock::new(Vec::new()));
+    let mut slots = serials.write().unwrap_or_else(|e| {
+        panic!("Failed to lock SERIALS for writing: {e:?}");
+    });
+
+    // reuse a freed slot/token if one is available, otherwise grow the registry
+    let token = indices.free.pop().unwrap_or(slots.len());
+
+    mio_ctx
+        .registry
+        .register(&mut stream, Token(token), Interest::READABLE)
+        .unwrap_or_else(|e| {
+            panic!("Failed to register stream to poll : {e:?}");
+        });
+
+    let labeled = Mutex::new(SerialStreamLabeled {
+        stream,
+        label: label.clone(),
+        connected: true,
+        setting: setting.clone(),
+        reconnect_state: None,
+        reconnecting: false,
+        framing_buffer: Vec::new(),
+    });
+
+    if token == slots.len() {
+        slots.push(Some(labeled));
+    } else {
+        slots[token] = Some(labeled);
+    }
+    drop(slots);
+
+    indices.map.insert(label, token);
+}
+
+/// Deregister and drop the serial port registered under `label`, freeing its
+/// token/index so a future `SerialOpenEvent` can reuse it.
+fn close_serial_port(label: &str, mio_ctx: &mut MioContext, indices: &mut Indices) {
+    let Some(token) = indices.map.remove(label) else {
+        eprintln!("Label {label} does not exist, cannot close");
+        return;
+    };
+
+    let serials = SERIALS.get().expect("SERIALS are not initialized");
+    let mut slots = serials.write().unwrap_or_else(|e| {
+        panic!("Failed to lock SERIALS for writing: {e:?}");
+    });
+
+    if let Some(serial_mtx) = slots[token].take() {
+        let mut serial = serial_mtx.into_inner().unwrap_or_else(|e| {
+            panic!("Failed to unlock serial port mutex: {e:?}");
+        });
+        if let Err(e) = mio_ctx.registry.deregister(&mut serial.stream) {
+            eprintln!("Failed to deregister serial port {}: {e:?}", serial.label);
+        }
+    }
+    drop(slots);
+
+    indices.free.push(token);
+}
+
+/// Bevy's event type to open a new serial port at runtime, on top of the
+/// ports listed in `SerialPlugin::settings`.
+#[derive(Event)]
+pub struct SerialOpenEvent(pub SerialSetting);
+
+/// Bevy's event type to close a serial port opened via `SerialPlugin::settings`
+/// or a previous `SerialOpenEvent`.
+#[derive(Event)]
+pub struct SerialCloseEvent(pub String);
+
+/// Open the serial ports requested via `SerialOpenEvent` since the last frame.
+fn open_serial(
+    mut ev_open: EventReader<SerialOpenEvent>,
     mut mio_ctx: ResMut<MioContext>,
-    indices: Res<Indices>,
-) -> std::io::Result<()> {
-    if !indices.0.is_empty() {
-        // poll serial read events
-        mio_ctx.poll();
+    mut indices: ResMut<Indices>,
+) {
+    for SerialOpenEvent(setting) in ev_open.read() {
+        open_serial_port(setting, &mut mio_ctx, &mut indices);
+    }
+}
 
-        // if events have occurred, send `SerialReadEvent` with serial labels and read data buffer
-        for event in mio_ctx.events.iter() {
-            // get serial instance based on the token index
-            let serials = SERIALS.get().expect("SERIALS are not initialized");
-            let serial_mtx = serials
-                .get(event.token().0) // token index is same as index of vec
-                .expect("SERIALS are not initialized");
+/// Close the serial ports requested via `SerialCloseEvent` since the last frame.
+fn close_serial(
+    mut ev_close: EventReader<SerialCloseEvent>,
+    mut mio_ctx: ResMut<MioContext>,
+    mut indices: ResMut<Indices>,
+) {
+    for SerialCloseEvent(label) in ev_close.read() {
+        close_serial_port(label, &mut mio_ctx, &mut indices);
+    }
+}
 
-            if event.is_readable() {
-                let mut buffer = vec![0_u8; DEFAULT_READ_BUFFER_LEN];
-                let mut bytes_read = 0;
-                loop {
-                    // try to get lock of mutex and send data to event
-                    if let Ok(mut serial) = serial_mtx.lock() {
-                        if serial.connected {
-                            match serial.stream.read(&mut buffer[bytes_read..]) {
-                                Ok(0) => {
-                                    eprintln!("read connection closed");
-                                    serial.connected = false;
-                                    break;
-                                }
-                                // read data successfully
-                                // if buffer is full, maybe there is more data to read
-                                Ok(n) => {
-                                    bytes_read += n;
-                                    if bytes_read == buffer.len() {
-                                        buffer.resize(buffer.len() + DEFAULT_READ_BUFFER_LEN, 0);
-                                    }
-                                }
-                                // would block indicates no more data to read
-                                Err(ref e) if e.kind() == ErrorKind::WouldBlock => {
-                                    let label = serial.label.clone();
-                                    let buffer = buffer.drain(..bytes_read).collect();
-                                    ev_receive_serial.send(SerialReadEvent(label, buffer));
-                                    break;
-                                }
-                                // if interrupted, we should continue readings
-                                Err(ref e) if e.kind() == ErrorKind::Interrupted => {
-                                    continue;
-                                }
-                                // other errors are fatal
-                                Err(e) => {
-                                    eprintln!("Failed to read serial port {}: {}", serial.label, e);
-                                }
-                            }
-                        } else {
-                            eprintln!("{} connection has closed", serial.label);
-                        }
-                    }
+/// `Commands`-like system param to open and close serial ports at runtime
+/// without constructing `SerialOpenEvent`/`SerialCloseEvent` by hand. Like
+/// `Commands`, the requests are deferred: they are only applied once
+/// `open_serial`/`close_serial` run in `PreUpdate`.
+#[derive(SystemParam)]
+pub struct SerialPortCommands<'w> {
+    open_events: EventWriter<'w, SerialOpenEvent>,
+    close_events: EventWriter<'w, SerialCloseEvent>,
+}
+
+impl<'w> SerialPortCommands<'w> {
+    /// Queue opening a new serial port described by `setting`.
+    pub fn open(&mut self, setting: SerialSetting) {
+        self.open_events.send(SerialOpenEvent(setting));
+    }
+
+    /// Queue closing the serial port registered under `label`.
+    pub fn close(&mut self, label: impl Into<String>) {
+        self.close_events.send(SerialCloseEvent(label.into()));
+    }
+}
+
+/// Command to set an output modem/handshake line on a serial port.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SerialControlCommand {
+    /// Assert (`true`) or clear (`false`) Request To Send
+    SetRequestToSend(bool),
+    /// Assert (`true`) or clear (`false`) Data Terminal Ready
+    SetDataTerminalReady(bool),
+}
+
+/// Bevy's event type to set a modem/handshake line on a serial port, e.g. to
+/// reset a microcontroller by toggling DTR.
+#[derive(Event)]
+pub struct SerialControlEvent(pub String, pub SerialControlCommand);
+
+/// Apply the modem/handshake line changes requested via `SerialControlEvent`
+/// since the last frame.
+fn control_serial(mut ev_control: EventReader<SerialControlEvent>, indices: Res<Indices>) {
+    for SerialControlEvent(label, command) in ev_control.read() {
+        with_connected_serial(&indices, label, |serial| {
+            let result = match *command {
+                SerialControlCommand::SetRequestToSend(value) => {
+                    serial.stream.write_request_to_send(value)
                 }
+                SerialControlCommand::SetDataTerminalReady(value) => {
+                    serial.stream.write_data_terminal_ready(value)
+                }
+            };
+            if let Err(e) = result {
+                eprintln!(
+                    "Failed to set control line on serial port {}: {e:?}",
+                    serial.label
+                );
+            }
+        });
+    }
+}
+
+/// Look up the serial port registered under `label` and, if it exists and is
+/// currently connected, run `f` on it while holding its lock. Used by the
+/// systems that act on an already-open port by label (control lines,
+/// reconfiguration, buffer clearing) to share the same lookup/locking dance.
+fn with_connected_serial(indices: &Indices, label: &str, f: impl FnOnce(&mut SerialStreamLabeled)) {
+    let Some(&token) = indices.map.get(label) else {
+        eprintln!("Label {label} does not exist");
+        return;
+    };
+
+    let serials = SERIALS.get().expect("SERIALS are not initialized");
+    let slots = serials.read().unwrap_or_else(|e| {
+        panic!("Failed to lock SERIALS for reading: {e:?}");
+    });
+    let Some(serial_mtx) = slots.get(token).and_then(|slot| slot.as_ref()) else {
+        return;
+    };
+    let mut serial = serial_mtx.lock().unwrap_or_else(|e| {
+        panic!("Failed to lock serial port mutex: {e:?}");
+    });
+
+    if !serial.connected {
+        eprintln!("{} connection has closed", serial.label);
+        return;
+    }
+
+    f(&mut serial);
+}
+
+/// Current state of a serial port's modem/handshake input lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SerialModemStatus {
+    /// Clear To Send
+    pub clear_to_send: bool,
+    /// Data Set Ready
+    pub data_set_ready: bool,
+    /// Carrier Detect
+    pub carrier_detect: bool,
+    /// Ring Indicator
+    pub ring_indicator: bool,
+}
+
+/// System param to read the current state of a serial port's modem/handshake
+/// input lines (CTS, DSR, CD, RI).
+#[derive(SystemParam)]
+pub struct SerialModemLines<'w> {
+    indices: Res<'w, Indices>,
+}
+
+impl<'w> SerialModemLines<'w> {
+    /// Read the current input line states for the serial port registered
+    /// under `label`. Returns `None` if the port doesn't exist, is closed,
+    /// or querying one of the line states failed.
+    pub fn read(&self, label: &str) -> Option<SerialModemStatus> {
+        let &token = self.indices.map.get(label)?;
+        let serials = SERIALS.get()?;
+        let slots = serials.read().unwrap_or_else(|e| {
+            panic!("Failed to lock SERIALS for reading: {e:?}");
+        });
+        let serial_mtx = slots.get(token).and_then(|slot| slot.as_ref())?;
+        let mut serial = serial_mtx.lock().unwrap_or_else(|e| {
+            panic!("Failed to lock serial port mutex: {e:?}");
+        });
+
+        if !serial.connected {
+            return None;
+        }
+
+        Some(SerialModemStatus {
+            clear_to_send: serial.stream.read_clear_to_send().ok()?,
+            data_set_ready: serial.stream.read_data_set_ready().ok()?,
+            carrier_detect: serial.stream.read_carrier_detect().ok()?,
+            ring_indicator: serial.stream.read_ring_indicator().ok()?,
+        })
+    }
+}
+
+/// Bevy's event type to reconfigure an already-open serial port's baud rate,
+/// data/stop bits, flow control, parity and timeout, without closing it.
+/// `label`, `port_name` and the reconnection/framing settings of the port
+/// are left untouched.
+#[derive(Event)]
+pub struct SerialReconfigureEvent(pub String, pub SerialSetting);
+
+/// Bevy's event type to flush a serial port's input and/or output buffers,
+/// e.g. to discard garbage left over after a device reset.
+#[derive(Event)]
+pub struct SerialClearEvent(pub String, pub ClearBuffer);
+
+/// Apply the port reconfigurations requested via `SerialReconfigureEvent`
+/// since the last frame.
+fn reconfigure_serial(
+    mut ev_reconfigure: EventReader<SerialReconfigureEvent>,
+    indices: Res<Indices>,
+) {
+    for SerialReconfigureEvent(label, setting) in ev_reconfigure.read() {
+        with_connected_serial(&indices, label, |serial| {
+            let result: std::io::Result<()> = (|| {
+                serial.stream.set_baud_rate(setting.baud_rate)?;
+                serial.stream.set_data_bits(setting.data_bits)?;
+                serial.stream.set_flow_control(setting.flow_control)?;
+                serial.stream.set_parity(setting.parity)?;
+                serial.stream.set_stop_bits(setting.stop_bits)?;
+                serial.stream.set_timeout(setting.timeout)?;
+                Ok(())
+            })();
+
+            if let Err(e) = result {
+                eprintln!("Failed to reconfigure serial port {}: {e:?}", serial.label);
+                return;
+            }
+
+            serial.setting.baud_rate = setting.baud_rate;
+            serial.setting.data_bits = setting.data_bits;
+            serial.setting.flow_control = setting.flow_control;
+            serial.setting.parity = setting.parity;
+            serial.setting.stop_bits = setting.stop_bits;
+            serial.setting.timeout = setting.timeout;
+        });
+    }
+}
+
+/// Flush the buffers requested via `SerialClearEvent` since the last frame.
+fn clear_serial(mut ev_clear: EventReader<SerialClearEvent>, indices: Res<Indices>) {
+    for SerialClearEvent(label, buffer_to_clear) in ev_clear.read() {
+        with_connected_serial(&indices, label, |serial| {
+            if let Err(e) = serial.stream.clear(*buffer_to_clear) {
+                eprintln!(
+                    "Failed to clear buffer on serial port {}: {e:?}",
+                    serial.label
+                );
+            }
+        });
+    }
+}
+
+/// Mark `serial` as disconnected, arming its reconnection backoff if
+/// `setting.reconnect` is set. Returns `true` the first time this is called
+/// on an already-connected port, so callers can notify exactly once via
+/// `SerialDisconnectedEvent`.
+fn mark_disconnected(serial: &mut SerialStreamLabeled) -> bool {
+    if !serial.connected {
+        return false;
+    }
+    serial.connected = false;
+    if let Some(policy) = serial.setting.reconnect {
+        serial.reconnect_state = Some(ReconnectState {
+            backoff: policy.initial_backoff,
+            next_attempt: Instant::now() + policy.initial_backoff,
+        });
+    }
+    true
+}
+
+/// Periodically check for ports that have disconnected and opted into
+/// `SerialSetting::reconnect` and whose backoff has elapsed, dispatching a
+/// reconnect attempt for each onto its own worker thread. Runs in
+/// `PreUpdate`, so — like `read_serial`/the background reader thread added in
+/// chunk0-6 — the blocking `SerialStream::open` call never runs on the frame
+/// thread; `reconnect_attempt` reports its result back over the same channel
+/// `read_serial` already drains.
+fn reconnect_serial(mut mio_ctx: ResMut<MioContext>, channel_sender: Res<SerialChannelSender>) {
+    let Some(serials) = SERIALS.get() else {
+        return;
+    };
+    let slots = serials.read().unwrap_or_else(|e| {
+        panic!("Failed to lock SERIALS for reading: {e:?}");
+    });
+
+    for (token, slot) in slots.iter().enumerate() {
+        let Some(serial_mtx) = slot else {
+            continue;
+        };
+        let mut serial = serial_mtx.lock().unwrap_or_else(|e| {
+            panic!("Failed to lock serial port mutex: {e:?}");
+        });
+
+        if serial.connected || serial.reconnecting {
+            continue;
+        }
+        if serial.setting.reconnect.is_none() {
+            continue;
+        }
+        let Some(state) = &serial.reconnect_state else {
+            continue;
+        };
+        if Instant::now() < state.next_attempt {
+            continue;
+        }
+
+        serial.reconnecting = true;
+        drop(serial);
+
+        let registry = mio_ctx.registry.try_clone().unwrap_or_else(|e| {
+            panic!("Failed to clone poll registry: {e:?}");
+        });
+        let sender = channel_sender.0.clone();
+        thread::Builder::new()
+            .name("bevy_serial reconnect".to_string())
+            .spawn(move || reconnect_attempt(token, registry, &sender))
+            .unwrap_or_else(|e| {
+                panic!("Failed to spawn serial reconnect thread: {e:?}");
+            });
+    }
+}
+
+/// Runs on a one-off worker thread spawned by `reconnect_serial` for the port
+/// registered under `token`: deregisters the stale stream (best-effort, like
+/// `close_serial_port` does when closing a port) so it can't collide with the
+/// replacement under the same `Token`, then blocks on `SerialStream::open`
+/// and either installs the reopened stream or schedules the next backoff
+/// attempt. Reports success back to `read_serial` over `sender`.
+fn reconnect_attempt(
+    token: usize,
+    registry: Registry,
+    sender: &mpsc::Sender<SerialChannelMessage>,
+) {
+    let Some(serials) = SERIALS.get() else {
+        return;
+    };
+    let slots = serials.read().unwrap_or_else(|e| {
+        panic!("Failed to lock SERIALS for reading: {e:?}");
+    });
+    let Some(serial_mtx) = slots.get(token).and_then(|slot| slot.as_ref()) else {
+        return;
+    };
+    let mut serial = serial_mtx.lock().unwrap_or_else(|e| {
+        panic!("Failed to lock serial port mutex: {e:?}");
+    });
+
+    let Some(policy) = serial.setting.reconnect else {
+        serial.reconnecting = false;
+        return;
+    };
+    let backoff = serial
+        .reconnect_state
+        .as_ref()
+        .map_or(policy.initial_backoff, |state| state.backoff);
+
+    let port_builder = serialport::new(&serial.setting.port_name, serial.setting.baud_rate)
+        .data_bits(serial.setting.data_bits)
+        .flow_control(serial.setting.flow_control)
+        .parity(serial.setting.parity)
+        .stop_bits(serial.setting.stop_bits)
+        .timeout(serial.setting.timeout);
+
+    let _ = registry.deregister(&mut serial.stream);
+
+    match SerialStream::open(&port_builder).and_then(|mut stream| {
+        registry.register(&mut stream, Token(token), Interest::READABLE)?;
+        Ok(stream)
+    }) {
+        Ok(stream) => {
+            serial.stream = stream;
+            serial.connected = true;
+            serial.reconnect_state = None;
+            serial.reconnecting = false;
+            serial.framing_buffer.clear();
+            let label = serial.label.clone();
+            drop(serial);
+            let _ = sender.send(SerialChannelMessage::Reconnected(label));
+        }
+        Err(e) => {
+            eprintln!("Failed to reconnect serial port {}: {e:?}", serial.label);
+            let backoff = policy.next_backoff(backoff);
+            serial.reconnect_state = Some(ReconnectState {
+                backoff,
+                next_attempt: Instant::now() + backoff,
+            });
+            serial.reconnecting = false;
+        }
+    }
+}
+
+/// Drain the messages pushed by the background reader thread since the last
+/// frame, turning each into a `SerialReadEvent`, `SerialDisconnectedEvent` or
+/// `SerialConnectedEvent`.
+fn read_serial(
+    mut ev_receive_serial: EventWriter<SerialReadEvent>,
+    mut ev_disconnected: EventWriter<SerialDisconnectedEvent>,
+    mut ev_connected: EventWriter<SerialConnectedEvent>,
+    channel: Res<SerialReadChannel>,
+) -> std::io::Result<()> {
+    let receiver = channel.receiver.lock().unwrap_or_else(|e| {
+        panic!("Failed to lock serial read channel receiver: {e:?}");
+    });
+    for message in receiver.try_iter() {
+        match message {
+            SerialChannelMessage::Read(label, buffer) => {
+                ev_receive_serial.send(SerialReadEvent(label, buffer));
+            }
+            SerialChannelMessage::Disconnected(label) => {
+                ev_disconnected.send(SerialDisconnectedEvent(label));
+            }
+            SerialChannelMessage::Reconnected(label) => {
+                ev_connected.send(SerialConnectedEvent(label));
             }
         }
     }
@@ -362,15 +1263,19 @@ fn write_serial(
     mut ev_write_serial: EventReader<SerialWriteEvent>,
     indices: Res<Indices>,
 ) -> std::io::Result<()> {
-    if !indices.0.is_empty() {
+    if !indices.map.is_empty() {
         for SerialWriteEvent(label, buffer) in ev_write_serial.read() {
             // get index of label
-            let &serial_index = indices.0.get(label).unwrap_or_else(|| {
+            let &serial_index = indices.map.get(label).unwrap_or_else(|| {
                 panic!("Label {} is not exist", label.as_str());
             });
             let serials = SERIALS.get().expect("SERIALS are not initialized");
-            let serial_mtx = serials
+            let slots = serials.read().unwrap_or_else(|e| {
+                panic!("Failed to lock SERIALS for reading: {e:?}");
+            });
+            let serial_mtx = slots
                 .get(serial_index)
+                .and_then(|slot| slot.as_ref())
                 .expect("SERIALS are not initialized");
 
             // write buffered data to serial
@@ -418,3 +1323,98 @@ fn write_serial(
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn length_header_size_byte_len() {
+        assert_eq!(LengthHeaderSize::One.byte_len(), 1);
+        assert_eq!(LengthHeaderSize::Two.byte_len(), 2);
+        assert_eq!(LengthHeaderSize::Four.byte_len(), 4);
+    }
+
+    #[test]
+    fn endianness_read_len() {
+        assert_eq!(Endianness::Big.read_len(&[0x01, 0x02]), 0x0102);
+        assert_eq!(Endianness::Little.read_len(&[0x01, 0x02]), 0x0201);
+        assert_eq!(Endianness::Big.read_len(&[0x00, 0x00, 0x00, 0x05]), 5);
+    }
+
+    #[test]
+    fn cobs_decode_no_zero_bytes() {
+        assert_eq!(cobs_decode(&[4, 1, 2, 3]), Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn cobs_decode_with_interior_zero() {
+        assert_eq!(cobs_decode(&[2, 1, 2, 2]), Ok(vec![1, 0, 2]));
+    }
+
+    #[test]
+    fn cobs_decode_empty() {
+        assert_eq!(cobs_decode(&[]), Ok(vec![]));
+    }
+
+    #[test]
+    fn cobs_decode_rejects_truncated_run() {
+        // code 3 claims 2 more bytes follow, only 1 is present
+        assert_eq!(cobs_decode(&[3, 1]), Err(()));
+    }
+
+    #[test]
+    fn frame_bytes_raw_emits_whatever_was_read() {
+        let mut buffer = Vec::new();
+        let frames = frame_bytes(&mut buffer, &[1, 2, 3], FramingMode::Raw);
+        assert_eq!(frames, vec![vec![1, 2, 3]]);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn frame_bytes_delimiter_splits_and_keeps_tail() {
+        let mut buffer = Vec::new();
+        let frames = frame_bytes(&mut buffer, b"ab\ncd\nef", FramingMode::Delimiter(b'\n'));
+        assert_eq!(frames, vec![b"ab".to_vec(), b"cd".to_vec()]);
+        assert_eq!(buffer, b"ef");
+    }
+
+    #[test]
+    fn frame_bytes_delimiter_accumulates_across_calls() {
+        let mut buffer = Vec::new();
+        assert!(frame_bytes(&mut buffer, b"ab", FramingMode::Delimiter(b'\n')).is_empty());
+        let frames = frame_bytes(&mut buffer, b"cd\n", FramingMode::Delimiter(b'\n'));
+        assert_eq!(frames, vec![b"abcd".to_vec()]);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn frame_bytes_length_prefixed_waits_for_full_frame() {
+        let mode = FramingMode::LengthPrefixed {
+            header_len: LengthHeaderSize::One,
+            endianness: Endianness::Big,
+        };
+        let mut buffer = Vec::new();
+        assert!(frame_bytes(&mut buffer, &[3, b'a', b'b'], mode).is_empty());
+        let frames = frame_bytes(&mut buffer, &[b'c'], mode);
+        assert_eq!(frames, vec![b"abc".to_vec()]);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn frame_bytes_cobs_decodes_complete_frames() {
+        let mut buffer = Vec::new();
+        let frames = frame_bytes(&mut buffer, &[4, 1, 2, 3, 0], FramingMode::Cobs);
+        assert_eq!(frames, vec![vec![1, 2, 3]]);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn frame_bytes_resets_buffer_past_max_len() {
+        let mut buffer = Vec::new();
+        let chunk = vec![b'x'; MAX_FRAMING_BUFFER_LEN + 1];
+        let frames = frame_bytes(&mut buffer, &chunk, FramingMode::Delimiter(b'\n'));
+        assert!(frames.is_empty());
+        assert!(buffer.is_empty());
+    }
+}